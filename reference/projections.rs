@@ -83,6 +83,8 @@ pub struct LatLonCoordinate {
     pub lon_decimal: f64,
     pub lat_dms: DMS,
     pub lon_dms: DMS,
+    pub utm: Option<String>,
+    pub mgrs: Option<String>,
 }
 
 /// Get the appropriate projection for a DCS theatre name
@@ -112,8 +114,70 @@ pub fn proj_from_map(map: &TransverseMercator) -> Result<Proj, anyhow::Error> {
     .map_err(|e| anyhow!("{:?}", e))
 }
 
-pub fn convert_dcs_lat_lon(x: f64, y: f64, proj: &Proj) -> (f64, f64) {
-    proj.convert((y, x)).unwrap()
+/// Convert a DCS (x, y) map coordinate to WGS84 lat/lon, validating the input and the
+/// projected result instead of panicking on a malformed coordinate
+pub fn convert_dcs_lat_lon(
+    x: f64,
+    y: f64,
+    proj: &Proj,
+    map: &TransverseMercator,
+    theatre: &str,
+) -> Result<(f64, f64), anyhow::Error> {
+    if !x.is_finite() || !y.is_finite() {
+        return Err(anyhow!(
+            "non-finite DCS coordinate ({}, {}) for theatre {}",
+            x,
+            y,
+            theatre
+        ));
+    }
+
+    // `Proj::convert` returns its result in the same (easting-like, northing-like) order
+    // as the input, i.e. (longitude, latitude) here
+    let (lon, lat) = proj
+        .convert((y, x))
+        .map_err(|e| anyhow!("failed to project ({}, {}) for theatre {}: {:?}", x, y, theatre, e))?;
+
+    if !lat.is_finite() || !(-90.0..=90.0).contains(&lat) {
+        return Err(anyhow!(
+            "latitude {} out of range for theatre {}",
+            lat,
+            theatre
+        ));
+    }
+
+    let central_meridian = map.central_meridian as f64;
+    if !lon.is_finite() || (lon - central_meridian).abs() > 90.0 {
+        return Err(anyhow!(
+            "longitude {} out of range for theatre {}",
+            lon,
+            theatre
+        ));
+    }
+
+    Ok((lon, lat))
+}
+
+/// Build the inverse of `proj_from_map`: a projection that takes WGS84 lat/lon and
+/// emits DCS map coordinates for a theatre
+pub fn inverse_proj_from_map(map: &TransverseMercator) -> Result<Proj, anyhow::Error> {
+    Proj::new_known_crs(
+        "WGS84",
+        &format!(
+            "+proj=tmerc +lat_0=0 +lon_0={} +k_0={} +x_0={} +y_0={}",
+            map.central_meridian, map.scale_factor, map.false_easting, map.false_northing
+        ),
+        None,
+    )
+    .map_err(|e| anyhow!("{:?}", e))
+}
+
+/// Convert a WGS84 lat/lon back into DCS (x, y) map coordinates for a theatre
+pub fn lat_lon_to_dcs(lat: f64, lon: f64, theatre: &str) -> Result<(f64, f64), anyhow::Error> {
+    let projection = projection_from_theatre(theatre)?;
+    let proj = inverse_proj_from_map(&projection)?;
+    let (y, x) = proj.convert((lon, lat)).map_err(|e| anyhow!("{:?}", e))?;
+    Ok((x, y))
 }
 
 pub fn offset(x_init: f64, y_init: f64, axis_deg: f64, distance_m: f64) -> (f64, f64) {
@@ -123,6 +187,62 @@ pub fn offset(x_init: f64, y_init: f64, axis_deg: f64, distance_m: f64) -> (f64,
     (x2, y2)
 }
 
+const EARTH_RADIUS_M: f64 = 6371000.0;
+
+/// Great-circle distance in meters between two WGS84 lat/lon points (haversine formula)
+pub fn distance_m(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let phi1 = lat1.to_radians();
+    let phi2 = lat2.to_radians();
+    let delta_phi = (lat2 - lat1).to_radians();
+    let delta_lambda = (lon2 - lon1).to_radians();
+
+    let a = (delta_phi / 2.0).sin().powi(2)
+        + phi1.cos() * phi2.cos() * (delta_lambda / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_M * a.sqrt().asin()
+}
+
+/// Initial great-circle bearing in degrees (0-360, clockwise from true north) from one
+/// WGS84 lat/lon point to another
+pub fn initial_bearing_deg(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let phi1 = lat1.to_radians();
+    let phi2 = lat2.to_radians();
+    let delta_lambda = (lon2 - lon1).to_radians();
+
+    let y = delta_lambda.sin() * phi2.cos();
+    let x = phi1.cos() * phi2.sin() - phi1.sin() * phi2.cos() * delta_lambda.cos();
+
+    (y.atan2(x).to_degrees() + 360.0) % 360.0
+}
+
+/// Destination WGS84 lat/lon reached by travelling `distance_m` along `bearing_deg` great
+/// circle from a starting point
+pub fn destination(lat: f64, lon: f64, bearing_deg: f64, distance_m: f64) -> (f64, f64) {
+    let angular_distance = distance_m / EARTH_RADIUS_M;
+    let bearing = bearing_deg.to_radians();
+    let phi1 = lat.to_radians();
+    let lambda1 = lon.to_radians();
+
+    let phi2 = (phi1.sin() * angular_distance.cos()
+        + phi1.cos() * angular_distance.sin() * bearing.cos())
+    .asin();
+    let lambda2 = lambda1
+        + (bearing.sin() * angular_distance.sin() * phi1.cos())
+            .atan2(angular_distance.cos() - phi1.sin() * phi2.sin());
+
+    (phi2.to_degrees(), lambda2.to_degrees())
+}
+
+/// Convert DMS (Degrees Minutes Seconds) back to a signed decimal degree value
+pub fn dms_to_decimal(dms: &DMS) -> f64 {
+    let value = dms.degrees as f64 + dms.minutes as f64 / 60.0 + dms.seconds / 3600.0;
+    if dms.direction == 'S' || dms.direction == 'W' {
+        -value
+    } else {
+        value
+    }
+}
+
 /// Convert decimal degrees to DMS (Degrees Minutes Seconds)
 pub fn decimal_to_dms(value: f64, is_latitude: bool) -> DMS {
     let abs_value = value.abs();
@@ -146,18 +266,144 @@ pub fn decimal_to_dms(value: f64, is_latitude: bool) -> DMS {
     }
 }
 
-/// Convert DCS coordinate to latitude/longitude and return both decimal and DMS format
-pub fn dcs_to_lat_lon_formatted(x: f64, y: f64, theatre: &str) -> Result<LatLonCoordinate, anyhow::Error> {
-    let projection = projection_from_theatre(theatre)?;
-    let proj = proj_from_map(&projection)?;
-    let (lat, lon) = convert_dcs_lat_lon(x, y, &proj);
-    
-    Ok(LatLonCoordinate {
+/// Build a `LatLonCoordinate` from a decimal lat/lon, filling in the DMS, UTM and MGRS
+/// representations
+fn coordinate_from_decimal(lat: f64, lon: f64) -> LatLonCoordinate {
+    LatLonCoordinate {
         lat_decimal: lat,
         lon_decimal: lon,
         lat_dms: decimal_to_dms(lat, true),
         lon_dms: decimal_to_dms(lon, false),
-    })
+        utm: Some(to_utm(lat, lon)),
+        mgrs: Some(to_mgrs(lat, lon, 5)),
+    }
+}
+
+/// Convert DCS coordinate to latitude/longitude and return it in decimal, DMS, UTM and MGRS format
+pub fn dcs_to_lat_lon_formatted(x: f64, y: f64, theatre: &str) -> Result<LatLonCoordinate, anyhow::Error> {
+    let projection = projection_from_theatre(theatre)?;
+    let proj = proj_from_map(&projection)?;
+    let (lon, lat) = convert_dcs_lat_lon(x, y, &proj, &projection, theatre)?;
+
+    Ok(coordinate_from_decimal(lat, lon))
+}
+
+const WGS84_SEMI_MAJOR_AXIS: f64 = 6378137.0;
+const WGS84_FLATTENING: f64 = 1.0 / 298.257223563;
+const UTM_SCALE_FACTOR: f64 = 0.9996;
+
+/// UTM zone number (1-60) for a given longitude
+fn utm_zone(lon: f64) -> i32 {
+    ((lon + 180.0) / 6.0).floor() as i32 + 1
+}
+
+/// MGRS latitude band letter ('C' through 'X', skipping 'I' and 'O')
+fn utm_latitude_band(lat: f64) -> char {
+    const BANDS: &str = "CDEFGHJKLMNPQRSTUVWXX";
+    let index = (((lat + 80.0) / 8.0).floor() as i32).clamp(0, 20) as usize;
+    BANDS.chars().nth(index).unwrap()
+}
+
+/// Project a WGS84 lat/lon onto the standard UTM grid, returning (zone, easting, northing)
+fn utm_projection(lat: f64, lon: f64) -> (i32, f64, f64) {
+    let a = WGS84_SEMI_MAJOR_AXIS;
+    let f = WGS84_FLATTENING;
+    let e2 = f * (2.0 - f);
+    let ep2 = e2 / (1.0 - e2);
+    let k0 = UTM_SCALE_FACTOR;
+
+    let zone = utm_zone(lon);
+    let lon_0 = (zone as f64 * 6.0 - 183.0).to_radians();
+    let phi = lat.to_radians();
+    let lambda = lon.to_radians();
+
+    let sin_phi = phi.sin();
+    let cos_phi = phi.cos();
+    let tan_phi = phi.tan();
+
+    let n = a / (1.0 - e2 * sin_phi * sin_phi).sqrt();
+    let t = tan_phi * tan_phi;
+    let c = ep2 * cos_phi * cos_phi;
+    let big_a = (lambda - lon_0) * cos_phi;
+
+    let m = a
+        * ((1.0 - e2 / 4.0 - 3.0 * e2 * e2 / 64.0 - 5.0 * e2.powi(3) / 256.0) * phi
+            - (3.0 * e2 / 8.0 + 3.0 * e2 * e2 / 32.0 + 45.0 * e2.powi(3) / 1024.0) * (2.0 * phi).sin()
+            + (15.0 * e2 * e2 / 256.0 + 45.0 * e2.powi(3) / 1024.0) * (4.0 * phi).sin()
+            - (35.0 * e2.powi(3) / 3072.0) * (6.0 * phi).sin());
+
+    let easting = k0
+        * n
+        * (big_a
+            + (1.0 - t + c) * big_a.powi(3) / 6.0
+            + (5.0 - 18.0 * t + t * t + 72.0 * c - 58.0 * ep2) * big_a.powi(5) / 120.0)
+        + 500_000.0;
+
+    let mut northing = k0
+        * (m
+            + n * tan_phi
+                * (big_a.powi(2) / 2.0
+                    + (5.0 - t + 9.0 * c + 4.0 * c * c) * big_a.powi(4) / 24.0
+                    + (61.0 - 58.0 * t + t * t + 600.0 * c - 330.0 * ep2) * big_a.powi(6) / 720.0));
+
+    if lat < 0.0 {
+        northing += 10_000_000.0;
+    }
+
+    (zone, easting, northing)
+}
+
+/// Format a WGS84 lat/lon as a UTM grid reference (zone, hemisphere, easting, northing)
+pub fn to_utm(lat: f64, lon: f64) -> String {
+    let (zone, easting, northing) = utm_projection(lat, lon);
+    let hemisphere = if lat >= 0.0 { 'N' } else { 'S' };
+    format!("{}{} {:.0} {:.0}", zone, hemisphere, easting, northing)
+}
+
+/// MGRS 100 km square identification letters for a given zone, easting and northing
+fn mgrs_square_letters(zone: i32, easting: f64, northing: f64) -> (char, char) {
+    const COLUMN_SETS: [&str; 3] = ["ABCDEFGH", "JKLMNPQR", "STUVWXYZ"];
+    const ROW_LETTERS_ODD: &str = "ABCDEFGHJKLMNPQRSTUV";
+    const ROW_LETTERS_EVEN: &str = "FGHJKLMNPQRSTUVABCDE";
+
+    let column_set = COLUMN_SETS[(zone - 1).rem_euclid(3) as usize];
+    let column_index = ((easting / 100_000.0).floor() as i64 - 1).rem_euclid(8) as usize;
+    let column_letter = column_set.chars().nth(column_index).unwrap();
+
+    let row_set = if zone % 2 == 0 { ROW_LETTERS_EVEN } else { ROW_LETTERS_ODD };
+    let row_index = ((northing / 100_000.0).floor() as i64).rem_euclid(20) as usize;
+    let row_letter = row_set.chars().nth(row_index).unwrap();
+
+    (column_letter, row_letter)
+}
+
+/// Truncate a UTM easting/northing to `precision` digits within its 100 km square
+fn mgrs_digits(value: f64, precision: usize) -> String {
+    if precision == 0 {
+        return String::new();
+    }
+    let within_square = (value as i64).rem_euclid(100_000);
+    let scaled = within_square / 10_i64.pow((5 - precision) as u32);
+    format!("{:0width$}", scaled, width = precision)
+}
+
+/// Format a WGS84 lat/lon as an MGRS grid reference, truncated to `precision` digits
+/// (1 = 10 km, 5 = 1 m) per easting/northing
+pub fn to_mgrs(lat: f64, lon: f64, precision: usize) -> String {
+    let precision = precision.clamp(0, 5);
+    let (zone, easting, northing) = utm_projection(lat, lon);
+    let band = utm_latitude_band(lat);
+    let (column_letter, row_letter) = mgrs_square_letters(zone, easting, northing);
+
+    format!(
+        "{}{} {}{} {}{}",
+        zone,
+        band,
+        column_letter,
+        row_letter,
+        mgrs_digits(easting, precision),
+        mgrs_digits(northing, precision),
+    )
 }
 
 /// Format DMS coordinates for display
@@ -178,6 +424,123 @@ pub fn format_coordinate(coord: &LatLonCoordinate) -> String {
     )
 }
 
+/// Split a string holding two `format_dms`-style tokens (e.g. `37°46'29.640"N 122°25'09.840"W`)
+/// into its latitude and longitude tokens
+fn split_dms_tokens(input: &str) -> Result<(String, String), anyhow::Error> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    for c in input.chars() {
+        current.push(c);
+        if matches!(c, 'N' | 'S' | 'E' | 'W') {
+            tokens.push(current.trim().trim_matches(',').trim().to_string());
+            current = String::new();
+        }
+    }
+    match tokens.as_slice() {
+        [lat, lon] => Ok((lat.clone(), lon.clone())),
+        _ => Err(anyhow!("expected two DMS coordinates in '{}'", input)),
+    }
+}
+
+/// Parse a single `format_dms`-style token into a `DMS`
+fn parse_dms_token(token: &str) -> Result<DMS, anyhow::Error> {
+    let token = token.trim();
+    let (degrees, rest) = token
+        .split_once('°')
+        .ok_or_else(|| anyhow!("missing degree symbol in '{}'", token))?;
+    let (minutes, rest) = rest
+        .split_once('\'')
+        .ok_or_else(|| anyhow!("missing minutes symbol in '{}'", token))?;
+    let (seconds, direction) = rest
+        .split_once('"')
+        .ok_or_else(|| anyhow!("missing seconds symbol in '{}'", token))?;
+    let direction = direction
+        .trim()
+        .chars()
+        .next()
+        .ok_or_else(|| anyhow!("missing hemisphere letter in '{}'", token))?;
+
+    Ok(DMS {
+        degrees: degrees.trim().parse().map_err(|_| anyhow!("invalid degrees in '{}'", token))?,
+        minutes: minutes.trim().parse().map_err(|_| anyhow!("invalid minutes in '{}'", token))?,
+        seconds: seconds.trim().parse().map_err(|_| anyhow!("invalid seconds in '{}'", token))?,
+        direction,
+    })
+}
+
+/// Parse a `DDMM.MMMM` NMEA degrees-decimal-minutes value followed by its hemisphere letter
+fn parse_nmea_token(token: &str) -> Result<f64, anyhow::Error> {
+    let token = token.trim();
+    let direction = token
+        .chars()
+        .last()
+        .filter(|c| matches!(c, 'N' | 'S' | 'E' | 'W'))
+        .ok_or_else(|| anyhow!("missing hemisphere letter in '{}'", token))?;
+    let value: f64 = token[..token.len() - 1]
+        .parse()
+        .map_err(|_| anyhow!("invalid NMEA coordinate '{}'", token))?;
+
+    let degrees = (value / 100.0).trunc();
+    let minutes = value - degrees * 100.0;
+    let decimal = degrees + minutes / 60.0;
+    Ok(if matches!(direction, 'S' | 'W') { -decimal } else { decimal })
+}
+
+/// Parse an NMEA lat/lon pair, accepting both `3953.4210N 07723.8654W` and
+/// `3953.4210,N,07723.8654,W` forms
+fn parse_nmea_pair(input: &str) -> Result<(f64, f64), anyhow::Error> {
+    let tokens: Vec<&str> = input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    match tokens.as_slice() {
+        [lat, lon] => Ok((parse_nmea_token(lat)?, parse_nmea_token(lon)?)),
+        [lat_value, lat_hem, lon_value, lon_hem] => {
+            let lat = parse_nmea_token(&format!("{}{}", lat_value, lat_hem.trim()))?;
+            let lon = parse_nmea_token(&format!("{}{}", lon_value, lon_hem.trim()))?;
+            Ok((lat, lon))
+        }
+        _ => Err(anyhow!("expected an NMEA lat/lon pair in '{}'", input)),
+    }
+}
+
+/// Parse a plain decimal degree lat/lon pair, e.g. `37.7749, -122.4194`
+fn parse_decimal_pair(input: &str) -> Result<(f64, f64), anyhow::Error> {
+    let parts: Vec<&str> = input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    match parts.as_slice() {
+        [lat, lon] => {
+            let lat = lat.parse().map_err(|_| anyhow!("invalid latitude '{}'", lat))?;
+            let lon = lon.parse().map_err(|_| anyhow!("invalid longitude '{}'", lon))?;
+            Ok((lat, lon))
+        }
+        _ => Err(anyhow!("expected a decimal lat/lon pair in '{}'", input)),
+    }
+}
+
+/// Parse a coordinate pasted by a user, detecting DMS (`format_dms` output), NMEA
+/// degrees-decimal-minutes, or plain decimal degree notation
+pub fn parse_coordinate(input: &str) -> Result<LatLonCoordinate, anyhow::Error> {
+    let trimmed = input.trim();
+
+    if trimmed.contains('°') {
+        let (lat_token, lon_token) = split_dms_tokens(trimmed)?;
+        let lat = dms_to_decimal(&parse_dms_token(&lat_token)?);
+        let lon = dms_to_decimal(&parse_dms_token(&lon_token)?);
+        Ok(coordinate_from_decimal(lat, lon))
+    } else if trimmed.chars().any(|c| matches!(c, 'N' | 'S' | 'E' | 'W')) {
+        let (lat, lon) = parse_nmea_pair(trimmed)?;
+        Ok(coordinate_from_decimal(lat, lon))
+    } else {
+        let (lat, lon) = parse_decimal_pair(trimmed)?;
+        Ok(coordinate_from_decimal(lat, lon))
+    }
+}
+
 /// Helper function to convert bullseye coordinates from a mission file
 pub fn convert_bullseye(x: f64, y: f64, theatre: &str) -> Result<LatLonCoordinate, anyhow::Error> {
     dcs_to_lat_lon_formatted(x, y, theatre)
@@ -190,19 +553,54 @@ pub fn convert_waypoint(x: f64, y: f64, theatre: &str) -> Result<LatLonCoordinat
 
 #[cfg(test)]
 mod tests {
-    use super::{convert_dcs_lat_lon, offset, decimal_to_dms, format_dms};
+    use super::{
+        convert_dcs_lat_lon, dcs_to_lat_lon_formatted, decimal_to_dms, destination, distance_m,
+        dms_to_decimal, format_dms, initial_bearing_deg, lat_lon_to_dcs, offset, parse_coordinate,
+        to_mgrs, to_utm,
+    };
     use crate::projections::{proj_from_map, PG};
     use approx_eq::assert_approx_eq;
 
     #[test]
     fn can_convert_to_lat_lon() {
-        let (x, y) =
-            convert_dcs_lat_lon(-100594.371094, -88875.371094, &proj_from_map(&PG).unwrap());
+        let (x, y) = convert_dcs_lat_lon(
+            -100594.371094,
+            -88875.371094,
+            &proj_from_map(&PG).unwrap(),
+            &PG,
+            "PersianGulf",
+        )
+        .unwrap();
 
         assert_approx_eq!(x, 55.3652612);
         assert_approx_eq!(y, 25.25637587);
     }
 
+    #[test]
+    fn convert_dcs_lat_lon_rejects_non_finite_input() {
+        let result = convert_dcs_lat_lon(
+            f64::NAN,
+            -88875.371094,
+            &proj_from_map(&PG).unwrap(),
+            &PG,
+            "PersianGulf",
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn convert_dcs_lat_lon_rejects_out_of_range_result() {
+        // Far outside the Persian Gulf theatre's projection band
+        let result = convert_dcs_lat_lon(
+            50_000_000.0,
+            50_000_000.0,
+            &proj_from_map(&PG).unwrap(),
+            &PG,
+            "PersianGulf",
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn add_dist_90deg() {
         let (x, y) = (10., 20.);
@@ -250,6 +648,98 @@ mod tests {
         assert_eq!(lon.direction, 'W');
     }
     
+    #[test]
+    fn test_dms_to_decimal() {
+        let dms = decimal_to_dms(37.7749, true);
+        assert_approx_eq!(dms_to_decimal(&dms), 37.7749, 1e-3);
+
+        let dms = decimal_to_dms(-122.4194, false);
+        assert_approx_eq!(dms_to_decimal(&dms), -122.4194, 1e-3);
+    }
+
+    #[test]
+    fn dcs_to_lat_lon_formatted_assigns_lat_and_lon_correctly() {
+        let coord =
+            dcs_to_lat_lon_formatted(-100594.371094, -88875.371094, "PersianGulf").unwrap();
+        assert_approx_eq!(coord.lat_decimal, 25.25637587);
+        assert_approx_eq!(coord.lon_decimal, 55.3652612);
+    }
+
+    #[test]
+    fn lat_lon_to_dcs_matches_known_point() {
+        // Same Persian Gulf DCS/WGS84 pair verified directly in can_convert_to_lat_lon,
+        // seeded here as plain numbers rather than piped through
+        // dcs_to_lat_lon_formatted/coordinate_from_decimal.
+        let (x, y) = lat_lon_to_dcs(25.25637587, 55.3652612, "PersianGulf").unwrap();
+        assert!((x - (-100594.371094)).abs() < 1.0);
+        assert!((y - (-88875.371094)).abs() < 1.0);
+    }
+
+    #[test]
+    fn round_trip_lat_lon_to_dcs() {
+        let (orig_x, orig_y) = (-100594.371094, -88875.371094);
+        let coord = dcs_to_lat_lon_formatted(orig_x, orig_y, "PersianGulf").unwrap();
+        let (x, y) = lat_lon_to_dcs(coord.lat_decimal, coord.lon_decimal, "PersianGulf").unwrap();
+        assert!((x - orig_x).abs() < 1.0);
+        assert!((y - orig_y).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_to_utm_zone_and_hemisphere() {
+        // San Francisco sits in UTM zone 10, northern hemisphere
+        let utm = to_utm(37.7749, -122.4194);
+        assert!(utm.starts_with("10N"));
+    }
+
+    #[test]
+    fn test_to_mgrs_known_pair() {
+        // On the equator at a zone's central meridian, easting is exactly 500000
+        // and northing is exactly 0, so the grid reference is exact.
+        let mgrs = to_mgrs(0.0, 3.0, 5);
+        assert_eq!(mgrs, "31N EA 00000 00000");
+    }
+
+    #[test]
+    fn test_parse_coordinate_dms() {
+        let coord = parse_coordinate("37°46'29.640\"N 122°25'09.840\"W").unwrap();
+        assert_approx_eq!(coord.lat_decimal, 37.7749, 1e-3);
+        assert_approx_eq!(coord.lon_decimal, -122.4194, 1e-3);
+    }
+
+    #[test]
+    fn test_parse_coordinate_decimal() {
+        let coord = parse_coordinate("37.7749, -122.4194").unwrap();
+        assert_approx_eq!(coord.lat_decimal, 37.7749, 1e-6);
+        assert_approx_eq!(coord.lon_decimal, -122.4194, 1e-6);
+    }
+
+    #[test]
+    fn test_parse_coordinate_nmea() {
+        let coord = parse_coordinate("3946.4940,N,12225.1640,W").unwrap();
+        assert_approx_eq!(coord.lat_decimal, 39.774900, 1e-4);
+        assert_approx_eq!(coord.lon_decimal, -122.419400, 1e-4);
+    }
+
+    #[test]
+    fn test_distance_m_one_degree_at_equator() {
+        // One degree of longitude at the equator is ~111.19 km
+        let distance = distance_m(0.0, 0.0, 0.0, 1.0);
+        assert!((distance - 111_194.9).abs() < 50.0);
+    }
+
+    #[test]
+    fn test_initial_bearing_deg_due_east() {
+        let bearing = initial_bearing_deg(0.0, 0.0, 0.0, 1.0);
+        assert_approx_eq!(bearing, 90.0, 1e-6);
+    }
+
+    #[test]
+    fn test_destination_matches_distance_and_bearing() {
+        let (lat, lon) = destination(0.0, 0.0, 90.0, 111_194.9);
+        assert_approx_eq!(lat, 0.0, 1e-3);
+        assert_approx_eq!(lon, 1.0, 1e-3);
+    }
+
     #[test]
     fn test_dms_formatting() {
         let dms = decimal_to_dms(37.7749, true);